@@ -3,14 +3,57 @@ use v_queue::consumer::Consumer;
 use v_queue::queue::Queue;
 use v_queue::record::MsgType;
 use v_queue::record::Mode;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use pyo3::prelude::*;
-use pyo3::exceptions::PyValueError;
-use pyo3::types::PyBytes;
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::types::{PyAny, PyBytes, PyDateTime, PyDict, PyList};
 use pyo3::PyObject;
 
+create_exception!(vqueue, QueueError, PyException);
+create_exception!(vqueue, QueueNotReady, QueueError);
+create_exception!(vqueue, QueueEmpty, QueueError);
+create_exception!(vqueue, InvalidChecksum, QueueError);
+create_exception!(vqueue, ReadError, QueueError);
+create_exception!(vqueue, WriteError, QueueError);
+
+/// Maps a queue-open/constructor failure to the matching `QueueError` subclass.
+fn open_error(e: ErrorQueue) -> PyErr {
+    match e {
+        ErrorQueue::NotReady => QueueNotReady::new_err(e.as_str().to_string()),
+        ErrorQueue::InvalidChecksum => InvalidChecksum::new_err(e.as_str().to_string()),
+        _ => QueueError::new_err(e.as_str().to_string()),
+    }
+}
+
+/// Maps a `pop_header`/`pop_body` failure to the matching `QueueError` subclass.
+fn read_error(e: ErrorQueue) -> PyErr {
+    match e {
+        ErrorQueue::NotReady => QueueNotReady::new_err(e.as_str().to_string()),
+        ErrorQueue::FailReadTailMessage => QueueEmpty::new_err(e.as_str().to_string()),
+        ErrorQueue::InvalidChecksum => InvalidChecksum::new_err(e.as_str().to_string()),
+        _ => ReadError::new_err(e.as_str().to_string()),
+    }
+}
+
+/// Maps a `push` failure to the matching `QueueError` subclass.
+fn write_error(e: ErrorQueue) -> PyErr {
+    match e {
+        ErrorQueue::NotReady => QueueNotReady::new_err(e.as_str().to_string()),
+        ErrorQueue::InvalidChecksum => InvalidChecksum::new_err(e.as_str().to_string()),
+        _ => WriteError::new_err(e.as_str().to_string()),
+    }
+}
+
 // Import from external library
 use v_individual_model::onto::individual::{Individual, RawObj};
 use v_individual_model::onto::parser::parse_raw;
+use v_individual_model::onto::individual2msgpack::to_msgpack;
+use v_individual_model::onto::datatype::{DataType, Lang};
+use v_individual_model::onto::resource::Resource;
+use serde_json::Value as JsonValue;
+use base64::Engine;
 
 #[pyclass(name = "Mode")]
 #[derive(Clone, Copy)]
@@ -83,7 +126,7 @@ impl PyQueue {
     fn new(base_path: String, queue_name: String, mode: PyMode) -> PyResult<Self> {
         match Queue::new(&base_path, &queue_name, mode.into()) {
             Ok(queue) => Ok(PyQueue { inner: queue }),
-            Err(e) => Err(PyValueError::new_err(e.as_str().to_string())),
+            Err(e) => Err(open_error(e)),
         }
     }
 
@@ -93,7 +136,18 @@ impl PyQueue {
 
         match self.inner.push(&bytes, msg_type.into()) {
             Ok(pos) => Ok(pos),
-            Err(e) => Err(PyValueError::new_err(e.as_str().to_string())),
+            Err(e) => Err(write_error(e)),
+        }
+    }
+
+    /// Encodes a JSON-LD document (string or dict) into Individual Object
+    /// format and pushes it, the reverse of `Consumer.convert_individual_to_dict`.
+    fn push_json(&mut self, py: Python<'_>, data: PyObject) -> PyResult<u64> {
+        let bytes = convert_json_to_individual_bytes(py, data)?;
+
+        match self.inner.push(&bytes, MsgType::Object) {
+            Ok(pos) => Ok(pos),
+            Err(e) => Err(write_error(e)),
         }
     }
 
@@ -116,23 +170,56 @@ impl PyQueue {
 #[pyclass(name = "Consumer")]
 pub struct PyConsumer {
     inner: Consumer,
+    auto_commit: bool,
 }
 
 #[pymethods]
 impl PyConsumer {
     #[new]
-    fn new(base_path: String, consumer_name: String, queue_name: String) -> PyResult<Self> {
+    #[pyo3(signature = (base_path, consumer_name, queue_name, auto_commit = false))]
+    fn new(base_path: String, consumer_name: String, queue_name: String, auto_commit: bool) -> PyResult<Self> {
         match Consumer::new(&base_path, &consumer_name, &queue_name) {
-            Ok(consumer) => Ok(PyConsumer { inner: consumer }),
-            Err(e) => Err(PyValueError::new_err(e.as_str().to_string())),
+            Ok(consumer) => Ok(PyConsumer { inner: consumer, auto_commit }),
+            Err(e) => Err(open_error(e)),
         }
     }
 
     #[staticmethod]
-    fn new_with_mode(base_path: String, consumer_name: String, queue_name: String, mode: PyMode) -> PyResult<Self> {
+    #[pyo3(signature = (base_path, consumer_name, queue_name, mode, auto_commit = false))]
+    fn new_with_mode(base_path: String, consumer_name: String, queue_name: String, mode: PyMode, auto_commit: bool) -> PyResult<Self> {
         match Consumer::new_with_mode(&base_path, &consumer_name, &queue_name, mode.into()) {
-            Ok(consumer) => Ok(PyConsumer { inner: consumer }),
-            Err(e) => Err(PyValueError::new_err(e.as_str().to_string())),
+            Ok(consumer) => Ok(PyConsumer { inner: consumer, auto_commit }),
+            Err(e) => Err(open_error(e)),
+        }
+    }
+
+    fn __iter__(slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        if !self.inner.pop_header() {
+            return Ok(None);
+        }
+
+        let msg_size = self.inner.header.msg_length as usize;
+        let mut buffer = vec![0u8; msg_size];
+
+        match self.inner.pop_body(&mut buffer) {
+            Ok(_) => {
+                let payload = decode_message(py, &buffer, self.inner.header.msg_type)?;
+                if self.auto_commit {
+                    self.inner.commit();
+                }
+                Ok(Some(payload))
+            },
+            Err(e) => {
+                if e == ErrorQueue::FailReadTailMessage {
+                    Ok(None)
+                } else {
+                    Err(read_error(e))
+                }
+            }
         }
     }
 
@@ -153,10 +240,43 @@ impl PyConsumer {
                 if e == ErrorQueue::FailReadTailMessage {
                     Ok(None)
                 } else {
-                    Err(PyValueError::new_err(e.as_str().to_string()))
+                    Err(read_error(e))
+                }
+            }
+        }
+    }
+
+    /// Pops up to `max_count` records (or `get_batch_size()` if omitted) in a
+    /// single call, decoding each according to its `MsgType`. Stops early at
+    /// the end of the queue. Pair with `commit()` to finalize the whole batch.
+    #[pyo3(signature = (max_count = None))]
+    fn pop_batch(&mut self, py: Python<'_>, max_count: Option<u32>) -> PyResult<Vec<PyObject>> {
+        let cap = max_count.unwrap_or_else(|| self.inner.get_batch_size());
+        let mut results = Vec::new();
+
+        for _ in 0..cap {
+            if !self.inner.pop_header() {
+                break;
+            }
+
+            let msg_size = self.inner.header.msg_length as usize;
+            let mut buffer = vec![0u8; msg_size];
+
+            match self.inner.pop_body(&mut buffer) {
+                Ok(_) => {
+                    results.push(decode_message(py, &buffer, self.inner.header.msg_type)?);
+                },
+                Err(e) => {
+                    if e == ErrorQueue::FailReadTailMessage {
+                        break;
+                    } else {
+                        return Err(read_error(e));
+                    }
                 }
             }
         }
+
+        Ok(results)
     }
 
     /// Converts binary data in Individual format to JSON string
@@ -166,26 +286,102 @@ impl PyConsumer {
         // Convert PyObject to bytes
         let bytes = py_to_bytes(py, binary_data)?;
 
-        // Create Individual from binary data
-        let raw = RawObj::new(bytes);
-        let mut individual = Individual::new_raw(raw);
+        individual_to_json(&bytes)
+    }
 
-        // Parse the raw data (initial parsing)
-        if let Err(_) = parse_raw(&mut individual) {
-            return Err(PyValueError::new_err("Failed to parse binary data to Individual"));
-        }
+    /// Converts binary data in Individual format directly into a Python dict,
+    /// skipping the intermediate JSON string `convert_individual_to_json` produces.
+    #[staticmethod]
+    fn convert_individual_to_dict(py: Python<'_>, binary_data: PyObject) -> PyResult<PyObject> {
+        let bytes = py_to_bytes(py, binary_data)?;
+
+        individual_to_pydict(py, &bytes)
+    }
 
-        // Fully parse all predicates and resources (Individual uses lazy parsing)
-        individual.parse_all();
+    /// Encodes a JSON-LD document (string or dict) into Individual Object
+    /// binary format, the reverse of `convert_individual_to_dict`.
+    #[staticmethod]
+    fn convert_json_to_individual(py: Python<'_>, data: PyObject) -> PyResult<PyObject> {
+        let bytes = convert_json_to_individual_bytes(py, data)?;
+        Ok(PyBytes::new(py, &bytes).into())
+    }
 
-        // Convert Individual to JSON
-        let json_str = individual.get_obj().as_json_str();
+    /// Runs the full drain loop in Rust: pops and decodes each record, invokes
+    /// `callback` with its payload (and the `MsgType` name when `with_msg_type`
+    /// is set), and commits after every `commit_every` successful calls.
+    /// Returning `False` from `callback` stops the loop cleanly. Returns the
+    /// number of records processed.
+    ///
+    /// A record whose callback raises (with `stop_on_error=False`) still
+    /// counts toward `processed` and the commit cadence: the queue's commit
+    /// cursor has no way to skip a single record, so once this batch commits
+    /// the failed record is dropped, not retried.
+    ///
+    /// With `stop_on_error=True`, the raising record itself is never counted
+    /// or committed, but every record successfully handed to `callback` since
+    /// the last commit boundary is committed before the error propagates —
+    /// otherwise those already-processed records would be redelivered (and
+    /// re-invoke `callback`) on the next run. Only the raising record (and
+    /// anything still unread after it) is left for the caller to retry.
+    #[pyo3(signature = (callback, commit_every = 1, stop_on_error = false, with_msg_type = false))]
+    fn for_each(&mut self, py: Python<'_>, callback: PyObject, commit_every: u32, stop_on_error: bool, with_msg_type: bool) -> PyResult<u64> {
+        let mut processed: u64 = 0;
+        let mut since_commit: u32 = 0;
 
-        if json_str.is_empty() {
-            return Err(PyValueError::new_err("Failed to convert Individual to JSON"));
+        loop {
+            if !self.inner.pop_header() {
+                break;
+            }
+
+            let msg_size = self.inner.header.msg_length as usize;
+            let mut buffer = vec![0u8; msg_size];
+            let msg_type = self.inner.header.msg_type;
+
+            let payload = match self.inner.pop_body(&mut buffer) {
+                Ok(_) => decode_message(py, &buffer, msg_type)?,
+                Err(e) => {
+                    if e == ErrorQueue::FailReadTailMessage {
+                        break;
+                    } else {
+                        return Err(read_error(e));
+                    }
+                }
+            };
+
+            let call_result = if with_msg_type {
+                callback.call1(py, (payload, msg_type_name(msg_type)))
+            } else {
+                callback.call1(py, (payload,))
+            };
+
+            let keep_going = match call_result {
+                Ok(result) => result.extract::<bool>(py).unwrap_or(true),
+                Err(e) if stop_on_error => {
+                    if since_commit > 0 {
+                        self.inner.commit();
+                    }
+                    return Err(e);
+                },
+                Err(_) => true,
+            };
+
+            processed += 1;
+            let (next_since_commit, should_commit) = commit_cadence(since_commit, commit_every);
+            since_commit = next_since_commit;
+            if should_commit {
+                self.inner.commit();
+            }
+
+            if !keep_going {
+                break;
+            }
+        }
+
+        if since_commit > 0 {
+            self.inner.commit();
         }
 
-        Ok(json_str)
+        Ok(processed)
     }
 
     fn commit(&mut self) -> bool {
@@ -207,6 +403,453 @@ impl PyConsumer {
     }
 }
 
+/// Asyncio-friendly consumer that follows the tail of the queue: `await next()`
+/// suspends the coroutine (instead of busy-returning `None`) until a new
+/// record is appended, polling on a tokio timer with exponential backoff.
+///
+/// This relies on `v_queue::Consumer` being `Send` (it's moved into a tokio
+/// task behind `Arc<Mutex<_>>`, never held across an `.await`); if a future
+/// `v_queue` release makes `Consumer` `!Send`, this type stops compiling and
+/// needs a different design (e.g. driving the queue from a dedicated thread
+/// and bridging with a channel instead of sharing it across the runtime).
+///
+/// IMPORTANT: `async for msg in consumer` never raises `StopAsyncIteration`
+/// on its own — tail-following means "end of queue" isn't a real end, so the
+/// loop suspends forever waiting for the next write. Pass
+/// `stop_after_idle_ms` to the constructor to opt `__anext__` into stopping
+/// (rather than blocking) after that many idle milliseconds; leave it unset
+/// only if the consumer loop truly never ends. `next(timeout_ms=...)` is
+/// unaffected and keeps raising `QueueEmpty` on timeout either way.
+#[pyclass(name = "AsyncConsumer")]
+pub struct PyAsyncConsumer {
+    inner: Arc<Mutex<Consumer>>,
+    poll_interval_ms: u64,
+    max_backoff_ms: u64,
+    stop_after_idle_ms: Option<u64>,
+}
+
+#[pymethods]
+impl PyAsyncConsumer {
+    #[new]
+    #[pyo3(signature = (base_path, consumer_name, queue_name, poll_interval_ms = 50, max_backoff_ms = 1000, stop_after_idle_ms = None))]
+    fn new(base_path: String, consumer_name: String, queue_name: String, poll_interval_ms: u64, max_backoff_ms: u64, stop_after_idle_ms: Option<u64>) -> PyResult<Self> {
+        match Consumer::new(&base_path, &consumer_name, &queue_name) {
+            Ok(consumer) => Ok(PyAsyncConsumer {
+                inner: Arc::new(Mutex::new(consumer)),
+                poll_interval_ms,
+                max_backoff_ms,
+                stop_after_idle_ms,
+            }),
+            Err(e) => Err(open_error(e)),
+        }
+    }
+
+    /// Awaits the next record, suspending the coroutine until one is available.
+    /// `timeout_ms`, when given, raises `QueueEmpty` if no record arrives in time.
+    #[pyo3(signature = (timeout_ms = None))]
+    fn next<'p>(&self, py: Python<'p>, timeout_ms: Option<u64>) -> PyResult<Bound<'p, PyAny>> {
+        let inner = self.inner.clone();
+        let poll_interval_ms = self.poll_interval_ms;
+        let max_backoff_ms = self.max_backoff_ms;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let fut = wait_for_next_record(inner, poll_interval_ms, max_backoff_ms);
+
+            match timeout_ms {
+                Some(ms) => match tokio::time::timeout(Duration::from_millis(ms), fut).await {
+                    Ok(result) => result,
+                    Err(_) => Err(QueueEmpty::new_err("Timed out waiting for the next message")),
+                },
+                None => fut.await,
+            }
+        })
+    }
+
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Like `next(timeout_ms=stop_after_idle_ms)`, except a timeout raises
+    /// `StopAsyncIteration` instead of `QueueEmpty`, so `stop_after_idle_ms`
+    /// turns `async for` into a loop that ends after that much idle time
+    /// instead of following the tail forever.
+    fn __anext__<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let inner = self.inner.clone();
+        let poll_interval_ms = self.poll_interval_ms;
+        let max_backoff_ms = self.max_backoff_ms;
+        let stop_after_idle_ms = self.stop_after_idle_ms;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let fut = wait_for_next_record(inner, poll_interval_ms, max_backoff_ms);
+
+            match stop_after_idle_ms {
+                Some(ms) => match tokio::time::timeout(Duration::from_millis(ms), fut).await {
+                    Ok(result) => result,
+                    Err(_) => Err(pyo3::exceptions::PyStopAsyncIteration::new_err(())),
+                },
+                None => fut.await,
+            }
+        })
+    }
+}
+
+/// Polls `inner` for the next record, sleeping on a tokio timer between
+/// attempts with exponential backoff up to `max_backoff_ms`.
+async fn wait_for_next_record(inner: Arc<Mutex<Consumer>>, poll_interval_ms: u64, max_backoff_ms: u64) -> PyResult<PyObject> {
+    let mut backoff_ms = poll_interval_ms.max(1);
+
+    loop {
+        let record = {
+            let mut consumer = inner.lock().unwrap();
+
+            if !consumer.pop_header() {
+                None
+            } else {
+                let msg_size = consumer.header.msg_length as usize;
+                let mut buffer = vec![0u8; msg_size];
+
+                match consumer.pop_body(&mut buffer) {
+                    Ok(_) => Some(Ok((buffer, consumer.header.msg_type))),
+                    Err(ErrorQueue::FailReadTailMessage) => None,
+                    Err(e) => Some(Err(e)),
+                }
+            }
+        };
+
+        match record {
+            Some(Ok((buffer, msg_type))) => {
+                return Python::with_gil(|py| decode_message(py, &buffer, msg_type));
+            },
+            Some(Err(e)) => return Err(read_error(e)),
+            None => {
+                tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+                backoff_ms = (backoff_ms * 2).min(max_backoff_ms.max(poll_interval_ms.max(1)));
+            }
+        }
+    }
+}
+
+/// Pure commit-cadence bookkeeping for `for_each`: given the counter of
+/// records seen since the last commit, returns the updated counter and
+/// whether a commit is due now. `commit_every` of zero behaves like one.
+fn commit_cadence(since_commit: u32, commit_every: u32) -> (u32, bool) {
+    let since_commit = since_commit + 1;
+    if since_commit >= commit_every.max(1) {
+        (0, true)
+    } else {
+        (since_commit, false)
+    }
+}
+
+/// Name of a `MsgType` as exposed to Python, matching `PyMsgType::__str__`.
+fn msg_type_name(msg_type: MsgType) -> &'static str {
+    match msg_type {
+        MsgType::String => "STRING",
+        MsgType::Object => "OBJECT",
+    }
+}
+
+/// Decodes a raw queue record into the Python value a consumer should see:
+/// `OBJECT` messages are parsed into a JSON string, `STRING` messages are
+/// returned as raw bytes.
+fn decode_message(py: Python<'_>, buffer: &[u8], msg_type: MsgType) -> PyResult<PyObject> {
+    match msg_type {
+        MsgType::Object => {
+            let json_str = individual_to_json(buffer)?;
+            Ok(json_str.into_py(py))
+        },
+        MsgType::String => {
+            let bytes = PyBytes::new(py, buffer);
+            Ok(bytes.into())
+        },
+    }
+}
+
+/// Parses Individual binary data and renders it as a JSON string.
+/// Shared by `convert_individual_to_json` and the iteration/batch readers.
+fn individual_to_json(bytes: &[u8]) -> PyResult<String> {
+    let raw = RawObj::new(bytes.to_vec());
+    let mut individual = Individual::new_raw(raw);
+
+    if let Err(_) = parse_raw(&mut individual) {
+        return Err(PyValueError::new_err("Failed to parse binary data to Individual"));
+    }
+
+    individual.parse_all();
+
+    let json_str = individual.get_obj().as_json_str();
+
+    if json_str.is_empty() {
+        return Err(PyValueError::new_err("Failed to convert Individual to JSON"));
+    }
+
+    Ok(json_str)
+}
+
+/// Parses Individual binary data and builds a native Python dict mirroring
+/// Veda's JSON-LD shape, without going through an intermediate JSON string.
+/// This is this crate's own round-trippable convention (consumed back by
+/// `convert_json_to_individual`) — it is not guaranteed to match the exact
+/// key/type spelling `as_json_str()` (used by `convert_individual_to_json`)
+/// produces, since that string comes straight out of `v_individual_model`.
+fn individual_to_pydict(py: Python<'_>, bytes: &[u8]) -> PyResult<PyObject> {
+    let raw = RawObj::new(bytes.to_vec());
+    let mut individual = Individual::new_raw(raw);
+
+    if let Err(_) = parse_raw(&mut individual) {
+        return Err(PyValueError::new_err("Failed to parse binary data to Individual"));
+    }
+
+    individual.parse_all();
+
+    let dict = PyDict::new(py);
+    dict.set_item("@id", individual.get_id())?;
+
+    for predicate in individual.get_predicates() {
+        if let Some(resources) = individual.get_resources(&predicate) {
+            let values = PyList::empty(py);
+            for resource in resources {
+                values.append(resource_to_pydict(py, &resource)?)?;
+            }
+            dict.set_item(predicate, values)?;
+        }
+    }
+
+    Ok(dict.into())
+}
+
+/// Converts a single `Resource` into the `{"data": ..., "type": ..., "lang": ...}`
+/// shape used by this crate's JSON-LD dict representation. `Decimal` is carried
+/// as `{"mantissa": int, "exponent": int}` rather than a reconstructed float,
+/// so `convert_json_to_individual` can decode it back losslessly.
+fn resource_to_pydict(py: Python<'_>, resource: &Resource) -> PyResult<PyObject> {
+    let entry = PyDict::new(py);
+
+    let type_name = match resource.get_type() {
+        DataType::Uri => "Uri",
+        DataType::String => "String",
+        DataType::Integer => "Integer",
+        DataType::Decimal => "Decimal",
+        DataType::Boolean => "Boolean",
+        DataType::Datetime => "Datetime",
+        DataType::Binary => "Binary",
+        DataType::Unknown => "Unknown",
+    };
+    entry.set_item("type", type_name)?;
+
+    let data: PyObject = match resource.get_type() {
+        DataType::Uri => resource.get_uri().into_py(py),
+        DataType::String => {
+            let (value, lang) = resource.get_str();
+            if lang != Lang::None {
+                entry.set_item("lang", lang.to_string())?;
+            }
+            value.into_py(py)
+        },
+        DataType::Integer => resource.get_int().into_py(py),
+        DataType::Decimal => {
+            let (mantissa, exponent) = resource.get_num();
+            let pair = PyDict::new(py);
+            pair.set_item("mantissa", mantissa)?;
+            pair.set_item("exponent", exponent)?;
+            pair.into_py(py)
+        },
+        DataType::Boolean => resource.get_bool().into_py(py),
+        DataType::Datetime => {
+            PyDateTime::from_timestamp(py, resource.get_datetime() as f64, None)?.into_py(py)
+        },
+        DataType::Binary => PyBytes::new(py, &resource.get_binary()).into_py(py),
+        // An unrecognized/malformed resource type carries no reliable value to
+        // surface — report it as "Unknown" with no data rather than silently
+        // relabeling it as a (likely empty) Uri.
+        DataType::Unknown => py.None(),
+    };
+    entry.set_item("data", data)?;
+
+    Ok(entry.into())
+}
+
+/// Encodes a JSON-LD document from Python into the binary MsgPack
+/// representation `v_individual_model` expects on the wire. A `dict` (as
+/// produced by `convert_individual_to_dict`) is walked directly so its native
+/// `datetime.datetime`/`bytes`/mantissa-exponent values round-trip losslessly;
+/// a JSON-encoded `str` is parsed via `serde_json` instead, using
+/// epoch-seconds integers, base64 strings, and `{"mantissa", "exponent"}`
+/// objects in their place, since plain JSON text cannot carry native Python types.
+fn convert_json_to_individual_bytes(py: Python<'_>, data: PyObject) -> PyResult<Vec<u8>> {
+    let mut individual = if let Ok(dict) = data.downcast_bound::<PyDict>(py) {
+        individual_from_pydict(dict)?
+    } else if let Ok(s) = data.extract::<String>(py) {
+        let value: JsonValue = serde_json::from_str(&s).map_err(|e| PyValueError::new_err(format!("Invalid JSON: {}", e)))?;
+        individual_from_json_value(&value)?
+    } else {
+        return Err(PyValueError::new_err("Expected a JSON string or dict"));
+    };
+
+    to_msgpack(&mut individual).map_err(|e| PyValueError::new_err(format!("Failed to encode Individual: {}", e)))
+}
+
+/// Builds an `Individual` from the dict shape `individual_to_pydict` produces:
+/// `"@id"` is the individual's uri, every other key is a predicate holding a
+/// list of `{"data": ..., "type": ..., "lang": ...}` entries.
+fn individual_from_pydict(dict: &Bound<'_, PyDict>) -> PyResult<Individual> {
+    let mut individual = Individual::default();
+
+    if let Some(id) = dict.get_item("@id")? {
+        individual.set_id(&id.extract::<String>()?);
+    }
+
+    for (predicate, values) in dict.iter() {
+        let predicate = predicate.extract::<String>()?;
+        if predicate == "@id" {
+            continue;
+        }
+
+        let entries = values.downcast::<PyList>().map_err(|_| PyValueError::new_err(format!("Expected a list of values for predicate {}", predicate)))?;
+
+        for entry in entries.iter() {
+            let entry = entry.downcast::<PyDict>().map_err(|_| PyValueError::new_err(format!("Expected a {{data, type}} entry for predicate {}", predicate)))?;
+            add_resource_from_pydict(&mut individual, &predicate, entry)?;
+        }
+    }
+
+    Ok(individual)
+}
+
+/// Appends one native-Python `{"data": ..., "type": ..., "lang": ...}` entry
+/// to `individual`, dispatching on `"type"` to the matching `add_*` setter.
+fn add_resource_from_pydict(individual: &mut Individual, predicate: &str, entry: &Bound<'_, PyDict>) -> PyResult<()> {
+    let type_name = entry.get_item("type")?.ok_or_else(|| PyValueError::new_err(format!("Missing \"type\" for predicate {}", predicate)))?.extract::<String>()?;
+    let data = entry.get_item("data")?.ok_or_else(|| PyValueError::new_err(format!("Missing \"data\" for predicate {}", predicate)))?;
+
+    match type_name.as_str() {
+        "Uri" => individual.add_uri(predicate, &data.extract::<String>()?),
+        "String" => {
+            let s = data.extract::<String>()?;
+            let lang = entry.get_item("lang")?.and_then(|l| l.extract::<String>().ok());
+            individual.add_string(predicate, &s, lang.as_deref().map(lang_from_str).unwrap_or(Lang::None));
+        },
+        "Integer" => individual.add_integer(predicate, data.extract::<i64>()?),
+        "Decimal" => {
+            let pair = data.downcast::<PyDict>().map_err(|_| PyValueError::new_err(format!("Expected a {{mantissa, exponent}} object for predicate {}", predicate)))?;
+            let mantissa = pair.get_item("mantissa")?.ok_or_else(|| PyValueError::new_err(format!("Missing \"mantissa\" for predicate {}", predicate)))?.extract::<i64>()?;
+            let exponent = pair.get_item("exponent")?.ok_or_else(|| PyValueError::new_err(format!("Missing \"exponent\" for predicate {}", predicate)))?.extract::<i64>()?;
+            individual.add_decimal(predicate, mantissa, exponent);
+        },
+        "Boolean" => individual.add_bool(predicate, data.extract::<bool>()?),
+        "Datetime" => {
+            let dt = data.downcast::<PyDateTime>().map_err(|_| PyValueError::new_err(format!("Expected a datetime for predicate {}", predicate)))?;
+            let ts = dt.call_method0("timestamp")?.extract::<f64>()? as i64;
+            individual.add_datetime(predicate, ts);
+        },
+        "Binary" => {
+            let bytes = data.downcast::<PyBytes>().map_err(|_| PyValueError::new_err(format!("Expected bytes for predicate {}", predicate)))?;
+            individual.add_binary(predicate, bytes.as_bytes().to_vec());
+        },
+        "Unknown" => return Err(PyValueError::new_err(format!("Cannot encode an \"Unknown\"-typed resource for predicate {}", predicate))),
+        other => return Err(PyValueError::new_err(format!("Unrecognized resource type \"{}\" for predicate {}", other, predicate))),
+    }
+
+    Ok(())
+}
+
+/// Builds an `Individual` from plain JSON text, using the same shape as
+/// `individual_from_pydict` except `Datetime` is epoch-seconds and `Binary`
+/// is a base64 string, since JSON text cannot carry native Python types.
+fn individual_from_json_value(value: &JsonValue) -> PyResult<Individual> {
+    let obj = value.as_object().ok_or_else(|| PyValueError::new_err("Expected a JSON object"))?;
+
+    let mut individual = Individual::default();
+
+    if let Some(id) = obj.get("@id").and_then(|v| v.as_str()) {
+        individual.set_id(id);
+    }
+
+    for (predicate, values) in obj {
+        if predicate == "@id" {
+            continue;
+        }
+
+        let entries = values.as_array().ok_or_else(|| PyValueError::new_err(format!("Expected an array of values for predicate {}", predicate)))?;
+
+        for entry in entries {
+            add_resource_from_json_value(&mut individual, predicate, entry)?;
+        }
+    }
+
+    Ok(individual)
+}
+
+/// Appends one JSON-text `{"data": ..., "type": ..., "lang": ...}` entry to
+/// `individual`, dispatching on `"type"` to the matching `add_*` setter.
+fn add_resource_from_json_value(individual: &mut Individual, predicate: &str, entry: &JsonValue) -> PyResult<()> {
+    let (type_name, data, lang) = parse_resource_entry(entry).map_err(|msg| PyValueError::new_err(format!("{} for predicate {}", msg, predicate)))?;
+
+    match type_name {
+        "Uri" => {
+            let uri = data.as_str().ok_or_else(|| PyValueError::new_err(format!("Expected a string uri for predicate {}", predicate)))?;
+            individual.add_uri(predicate, uri);
+        },
+        "String" => {
+            let s = data.as_str().ok_or_else(|| PyValueError::new_err(format!("Expected a string value for predicate {}", predicate)))?;
+            individual.add_string(predicate, s, lang.map(lang_from_str).unwrap_or(Lang::None));
+        },
+        "Integer" => {
+            let i = data.as_i64().ok_or_else(|| PyValueError::new_err(format!("Expected an integer value for predicate {}", predicate)))?;
+            individual.add_integer(predicate, i);
+        },
+        "Decimal" => {
+            let (mantissa, exponent) = decimal_from_json(data).map_err(|msg| PyValueError::new_err(format!("{} for predicate {}", msg, predicate)))?;
+            individual.add_decimal(predicate, mantissa, exponent);
+        },
+        "Boolean" => {
+            let b = data.as_bool().ok_or_else(|| PyValueError::new_err(format!("Expected a boolean value for predicate {}", predicate)))?;
+            individual.add_bool(predicate, b);
+        },
+        "Datetime" => {
+            let ts = data.as_i64().ok_or_else(|| PyValueError::new_err(format!("Expected an epoch-seconds value for predicate {}", predicate)))?;
+            individual.add_datetime(predicate, ts);
+        },
+        "Binary" => {
+            let s = data.as_str().ok_or_else(|| PyValueError::new_err(format!("Expected a base64 string for predicate {}", predicate)))?;
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(s)
+                .map_err(|e| PyValueError::new_err(format!("Invalid base64 for predicate {}: {}", predicate, e)))?;
+            individual.add_binary(predicate, bytes);
+        },
+        "Unknown" => return Err(PyValueError::new_err(format!("Cannot encode an \"Unknown\"-typed resource for predicate {}", predicate))),
+        other => return Err(PyValueError::new_err(format!("Unrecognized resource type \"{}\" for predicate {}", other, predicate))),
+    }
+
+    Ok(())
+}
+
+/// Pure helper (no pyo3/Individual dependency, so it's testable in isolation):
+/// pulls the `"type"`/`"data"`/optional `"lang"` fields out of one JSON-LD entry.
+fn parse_resource_entry(entry: &JsonValue) -> Result<(&str, &JsonValue, Option<&str>), String> {
+    let type_name = entry.get("type").and_then(|v| v.as_str()).ok_or_else(|| "Missing \"type\"".to_string())?;
+    let data = entry.get("data").ok_or_else(|| "Missing \"data\"".to_string())?;
+    let lang = entry.get("lang").and_then(|v| v.as_str());
+    Ok((type_name, data, lang))
+}
+
+/// Pure helper (no pyo3/Individual dependency): reads a
+/// `{"mantissa": int, "exponent": int}` JSON object, the JSON-text
+/// counterpart of the dict path's native mantissa/exponent pair.
+fn decimal_from_json(value: &JsonValue) -> Result<(i64, i64), String> {
+    let mantissa = value.get("mantissa").and_then(|v| v.as_i64()).ok_or_else(|| "Missing or non-integer \"mantissa\"".to_string())?;
+    let exponent = value.get("exponent").and_then(|v| v.as_i64()).ok_or_else(|| "Missing or non-integer \"exponent\"".to_string())?;
+    Ok((mantissa, exponent))
+}
+
+fn lang_from_str(code: &str) -> Lang {
+    match code.to_uppercase().as_str() {
+        "RU" => Lang::Ru,
+        "EN" => Lang::En,
+        _ => Lang::None,
+    }
+}
+
 /// Helper function to convert PyObject to Vec<u8>
 fn py_to_bytes(py: Python<'_>, obj: PyObject) -> PyResult<Vec<u8>> {
     // Try to downcast to PyBytes
@@ -226,7 +869,232 @@ fn vqueue(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Add classes to the module
     m.add("Queue", py.get_type::<PyQueue>())?;
     m.add("Consumer", py.get_type::<PyConsumer>())?;
+    m.add("AsyncConsumer", py.get_type::<PyAsyncConsumer>())?;
     m.add("Mode", py.get_type::<PyMode>())?;
     m.add("MsgType", py.get_type::<PyMsgType>())?;
+
+    // Register the QueueError exception hierarchy
+    m.add("QueueError", py.get_type::<QueueError>())?;
+    m.add("QueueNotReady", py.get_type::<QueueNotReady>())?;
+    m.add("QueueEmpty", py.get_type::<QueueEmpty>())?;
+    m.add("InvalidChecksum", py.get_type::<InvalidChecksum>())?;
+    m.add("ReadError", py.get_type::<ReadError>())?;
+    m.add("WriteError", py.get_type::<WriteError>())?;
+
     Ok(())
+}
+
+// These tests exercise `Python::with_gil` directly, so running them needs
+// pyo3's `auto-initialize` feature, which conflicts with the `extension-module`
+// feature this crate builds with as a Python extension; the eventual manifest
+// should pull pyo3 in for `[dev-dependencies]` without `extension-module`, the
+// way pyo3 itself documents for testing extension-module crates.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Round-trips one `{"data", "type", "lang"}` entry under `predicate`
+    /// through `individual_from_pydict` -> `to_msgpack` -> `individual_to_pydict`
+    /// and returns the re-decoded entry dict for the caller to assert on.
+    fn roundtrip_pydict_entry<'py>(py: Python<'py>, predicate: &str, entry: &Bound<'py, PyDict>) -> Bound<'py, PyDict> {
+        let dict = PyDict::new(py);
+        dict.set_item("@id", "test:subject").unwrap();
+        let entries = PyList::empty(py);
+        entries.append(entry).unwrap();
+        dict.set_item(predicate, entries).unwrap();
+
+        let mut individual = individual_from_pydict(&dict).unwrap();
+        let bytes = to_msgpack(&mut individual).unwrap();
+        let out = individual_to_pydict(py, &bytes).unwrap();
+        let out_dict = out.downcast_bound::<PyDict>(py).unwrap().clone();
+
+        assert_eq!(out_dict.get_item("@id").unwrap().unwrap().extract::<String>().unwrap(), "test:subject");
+
+        let out_entries = out_dict.get_item(predicate).unwrap().unwrap();
+        let out_entries = out_entries.downcast::<PyList>().unwrap();
+        out_entries.get_item(0).unwrap().downcast::<PyDict>().unwrap().clone()
+    }
+
+    #[test]
+    fn dict_roundtrip_uri() {
+        Python::with_gil(|py| {
+            let entry = PyDict::new(py);
+            entry.set_item("type", "Uri").unwrap();
+            entry.set_item("data", "test:target").unwrap();
+            let out = roundtrip_pydict_entry(py, "rdf:type", &entry);
+            assert_eq!(out.get_item("type").unwrap().unwrap().extract::<String>().unwrap(), "Uri");
+            assert_eq!(out.get_item("data").unwrap().unwrap().extract::<String>().unwrap(), "test:target");
+        });
+    }
+
+    #[test]
+    fn dict_roundtrip_string_with_lang() {
+        Python::with_gil(|py| {
+            let entry = PyDict::new(py);
+            entry.set_item("type", "String").unwrap();
+            entry.set_item("data", "hello").unwrap();
+            entry.set_item("lang", "EN").unwrap();
+            let out = roundtrip_pydict_entry(py, "rdfs:label", &entry);
+            assert_eq!(out.get_item("data").unwrap().unwrap().extract::<String>().unwrap(), "hello");
+            assert_eq!(out.get_item("lang").unwrap().unwrap().extract::<String>().unwrap(), "EN");
+        });
+    }
+
+    #[test]
+    fn dict_roundtrip_integer() {
+        Python::with_gil(|py| {
+            let entry = PyDict::new(py);
+            entry.set_item("type", "Integer").unwrap();
+            entry.set_item("data", 42i64).unwrap();
+            let out = roundtrip_pydict_entry(py, "v-s:count", &entry);
+            assert_eq!(out.get_item("data").unwrap().unwrap().extract::<i64>().unwrap(), 42);
+        });
+    }
+
+    #[test]
+    fn dict_roundtrip_decimal_mantissa_exponent() {
+        Python::with_gil(|py| {
+            let entry = PyDict::new(py);
+            entry.set_item("type", "Decimal").unwrap();
+            let pair = PyDict::new(py);
+            pair.set_item("mantissa", 314i64).unwrap();
+            pair.set_item("exponent", -2i64).unwrap();
+            entry.set_item("data", pair).unwrap();
+            let out = roundtrip_pydict_entry(py, "v-s:price", &entry);
+            let out_pair = out.get_item("data").unwrap().unwrap();
+            let out_pair = out_pair.downcast::<PyDict>().unwrap();
+            assert_eq!(out_pair.get_item("mantissa").unwrap().unwrap().extract::<i64>().unwrap(), 314);
+            assert_eq!(out_pair.get_item("exponent").unwrap().unwrap().extract::<i64>().unwrap(), -2);
+        });
+    }
+
+    #[test]
+    fn dict_roundtrip_boolean() {
+        Python::with_gil(|py| {
+            let entry = PyDict::new(py);
+            entry.set_item("type", "Boolean").unwrap();
+            entry.set_item("data", true).unwrap();
+            let out = roundtrip_pydict_entry(py, "v-s:isActive", &entry);
+            assert!(out.get_item("data").unwrap().unwrap().extract::<bool>().unwrap());
+        });
+    }
+
+    #[test]
+    fn dict_roundtrip_datetime() {
+        Python::with_gil(|py| {
+            let entry = PyDict::new(py);
+            entry.set_item("type", "Datetime").unwrap();
+            let dt = PyDateTime::from_timestamp(py, 1_700_000_000.0, None).unwrap();
+            entry.set_item("data", dt).unwrap();
+            let out = roundtrip_pydict_entry(py, "v-s:created", &entry);
+            let out_dt = out.get_item("data").unwrap().unwrap();
+            let ts = out_dt.call_method0("timestamp").unwrap().extract::<f64>().unwrap();
+            assert_eq!(ts as i64, 1_700_000_000);
+        });
+    }
+
+    #[test]
+    fn dict_roundtrip_binary() {
+        Python::with_gil(|py| {
+            let entry = PyDict::new(py);
+            entry.set_item("type", "Binary").unwrap();
+            entry.set_item("data", PyBytes::new(py, b"payload")).unwrap();
+            let out = roundtrip_pydict_entry(py, "v-s:blob", &entry);
+            let out_bytes = out.get_item("data").unwrap().unwrap();
+            assert_eq!(out_bytes.downcast::<PyBytes>().unwrap().as_bytes(), b"payload");
+        });
+    }
+
+    #[test]
+    fn json_roundtrip_via_individual_from_json_value() {
+        Python::with_gil(|py| {
+            let value = serde_json::json!({
+                "@id": "test:subject",
+                "rdfs:label": [{"data": "hello", "type": "String", "lang": "EN"}],
+                "v-s:price": [{"data": {"mantissa": 314, "exponent": -2}, "type": "Decimal"}],
+                "v-s:blob": [{"data": base64::engine::general_purpose::STANDARD.encode(b"payload"), "type": "Binary"}],
+            });
+
+            let mut individual = individual_from_json_value(&value).unwrap();
+            let bytes = to_msgpack(&mut individual).unwrap();
+            let out = individual_to_pydict(py, &bytes).unwrap();
+            let out = out.downcast_bound::<PyDict>(py).unwrap();
+
+            assert_eq!(out.get_item("@id").unwrap().unwrap().extract::<String>().unwrap(), "test:subject");
+
+            let label_entries = out.get_item("rdfs:label").unwrap().unwrap();
+            let label_entry = label_entries.downcast::<PyList>().unwrap().get_item(0).unwrap();
+            let label_entry = label_entry.downcast::<PyDict>().unwrap();
+            assert_eq!(label_entry.get_item("data").unwrap().unwrap().extract::<String>().unwrap(), "hello");
+
+            let price_entries = out.get_item("v-s:price").unwrap().unwrap();
+            let price_entry = price_entries.downcast::<PyList>().unwrap().get_item(0).unwrap();
+            let price_entry = price_entry.downcast::<PyDict>().unwrap();
+            let price_pair = price_entry.get_item("data").unwrap().unwrap();
+            let price_pair = price_pair.downcast::<PyDict>().unwrap();
+            assert_eq!(price_pair.get_item("mantissa").unwrap().unwrap().extract::<i64>().unwrap(), 314);
+            assert_eq!(price_pair.get_item("exponent").unwrap().unwrap().extract::<i64>().unwrap(), -2);
+
+            let blob_entries = out.get_item("v-s:blob").unwrap().unwrap();
+            let blob_entry = blob_entries.downcast::<PyList>().unwrap().get_item(0).unwrap();
+            let blob_entry = blob_entry.downcast::<PyDict>().unwrap();
+            let blob_data = blob_entry.get_item("data").unwrap().unwrap();
+            assert_eq!(blob_data.downcast::<PyBytes>().unwrap().as_bytes(), b"payload");
+        });
+    }
+
+    #[test]
+    fn commit_cadence_commits_every_nth_record() {
+        let (since_commit, committed) = commit_cadence(0, 3);
+        assert_eq!((since_commit, committed), (1, false));
+        let (since_commit, committed) = commit_cadence(since_commit, 3);
+        assert_eq!((since_commit, committed), (2, false));
+        let (since_commit, committed) = commit_cadence(since_commit, 3);
+        assert_eq!((since_commit, committed), (0, true));
+    }
+
+    #[test]
+    fn commit_cadence_treats_zero_commit_every_as_one() {
+        assert_eq!(commit_cadence(0, 0), (0, true));
+    }
+
+    #[test]
+    fn decimal_from_json_round_trips_mantissa_exponent() {
+        let value = serde_json::json!({"mantissa": 314, "exponent": -2});
+        assert_eq!(decimal_from_json(&value), Ok((314, -2)));
+    }
+
+    #[test]
+    fn decimal_from_json_rejects_missing_fields() {
+        assert!(decimal_from_json(&serde_json::json!({"exponent": -2})).is_err());
+        assert!(decimal_from_json(&serde_json::json!({"mantissa": 314})).is_err());
+    }
+
+    #[test]
+    fn parse_resource_entry_reads_type_data_and_lang() {
+        let entry = serde_json::json!({"data": "hello", "type": "String", "lang": "RU"});
+        let (type_name, data, lang) = parse_resource_entry(&entry).unwrap();
+        assert_eq!(type_name, "String");
+        assert_eq!(data.as_str(), Some("hello"));
+        assert_eq!(lang, Some("RU"));
+    }
+
+    #[test]
+    fn parse_resource_entry_rejects_missing_type_or_data() {
+        assert!(parse_resource_entry(&serde_json::json!({"data": "hello"})).is_err());
+        assert!(parse_resource_entry(&serde_json::json!({"type": "String"})).is_err());
+    }
+
+    #[test]
+    fn lang_from_str_recognizes_known_codes_and_defaults_to_none() {
+        assert!(matches!(lang_from_str("ru"), Lang::Ru));
+        assert!(matches!(lang_from_str("EN"), Lang::En));
+        assert!(matches!(lang_from_str("xx"), Lang::None));
+    }
+
+    #[test]
+    fn msg_type_name_matches_pymsgtype_str() {
+        assert_eq!(msg_type_name(MsgType::String), "STRING");
+        assert_eq!(msg_type_name(MsgType::Object), "OBJECT");
+    }
 }
\ No newline at end of file